@@ -0,0 +1,129 @@
+// A cycle-breaking cousin of the Unix `tsort` utility: where `tsort` simply fails on cyclic
+// input, this tool computes a feedback arc set with a selectable heuristic, reports the arcs
+// it dropped, and then prints a topological ordering of what remains.
+use graphalgorithms_feedback_arc_set::fas::divide_and_conquer_by_order_heuristic::DivideAndConquerByOrderHeuristic;
+use graphalgorithms_feedback_arc_set::fas::greedy_gr_heuristic::GreedyGrHeuristic;
+use graphalgorithms_feedback_arc_set::graph::hash_table::{Edge, HashTable};
+use graphalgorithms_feedback_arc_set::ordering::topological_sort::TopologicalSort;
+use graphalgorithms_feedback_arc_set::tools::metis::graph_from_file;
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+#[derive(Clone, Copy)]
+enum Heuristic {
+  Greedy,
+  DivideAndConquer,
+}
+
+struct Args {
+  heuristic: Heuristic,
+  metis: bool,
+  path: Option<String>,
+}
+
+fn main() {
+  let args = parse_args();
+
+  let graph = if args.metis {
+    let path = args
+      .path
+      .as_deref()
+      .expect("--format metis requires an input file path");
+    graph_from_file(path)
+  } else {
+    read_edge_list(args.path.as_deref())
+  };
+
+  let removable_edges = feedback_arc_set(&graph, args.heuristic);
+
+  let mut acyclic_graph = graph.clone();
+  for edge in &removable_edges {
+    acyclic_graph.remove_edge(*edge);
+  }
+
+  println!("Removed arcs:");
+  for (source, target) in &removable_edges {
+    println!("{source} -> {target}");
+  }
+
+  println!("\nOrdering:");
+  for vertex in TopologicalSort::new(&acyclic_graph).sort() {
+    println!("{vertex}");
+  }
+}
+
+fn parse_args() -> Args {
+  let mut heuristic = Heuristic::DivideAndConquer;
+  let mut metis = false;
+  let mut path = None;
+
+  let mut arguments = env::args().skip(1);
+  while let Some(argument) = arguments.next() {
+    match argument.as_str() {
+      "--format" => {
+        let format = arguments.next().expect("--format requires a value");
+        metis = format == "metis";
+      }
+      "--heuristic" => {
+        let value = arguments.next().expect("--heuristic requires a value");
+        heuristic = match value.as_str() {
+          "greedy" => Heuristic::Greedy,
+          "divide-and-conquer" => Heuristic::DivideAndConquer,
+          other => panic!("unknown heuristic: {other}"),
+        };
+      }
+      other => path = Some(other.to_string()),
+    }
+  }
+
+  Args {
+    heuristic,
+    metis,
+    path,
+  }
+}
+
+fn read_edge_list(path: Option<&str>) -> HashTable {
+  let content = match path {
+    Some(path) => fs::read_to_string(path).expect("failed to read input file"),
+    None => {
+      let mut buffer = String::new();
+      io::stdin()
+        .read_to_string(&mut buffer)
+        .expect("failed to read stdin");
+      buffer
+    }
+  };
+
+  let edges: Vec<Edge> = content
+    .lines()
+    .filter(|line| !line.trim().is_empty())
+    .map(|line| {
+      let mut vertices = line.split_whitespace();
+      let source = vertices
+        .next()
+        .expect("expected a source vertex")
+        .parse()
+        .expect("invalid source vertex");
+      let target = vertices
+        .next()
+        .expect("expected a target vertex")
+        .parse()
+        .expect("invalid target vertex");
+      (source, target)
+    })
+    .collect();
+
+  HashTable::from_edges(edges.as_slice())
+}
+
+fn feedback_arc_set(graph: &HashTable, heuristic: Heuristic) -> HashSet<Edge> {
+  match heuristic {
+    Heuristic::Greedy => GreedyGrHeuristic::new(graph).feedback_arc_set(),
+    Heuristic::DivideAndConquer => {
+      DivideAndConquerByOrderHeuristic::new(graph).feedback_arc_set()
+    }
+  }
+}
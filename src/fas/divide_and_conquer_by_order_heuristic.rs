@@ -1,6 +1,6 @@
 use crate::graph::hash_table::{Direction, Edge, HashTable, VertexId};
 use crate::ordering::topological_sort::TopologicalSort;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /*
 Another heuristic by Eades, Smyth and Lin (ESL) (1989) finds a feedback arc set of
@@ -39,12 +39,21 @@ impl<'a> DivideAndConquerByOrderHeuristic<'a> {
   }
 }
 
-fn collect_leftward_edges(graph: &HashTable, ordering: Vec<VertexId>) -> HashSet<Edge> {
+// Ranks edges by each vertex's *position in `ordering`*, not by raw `VertexId`: the
+// heuristics above only guarantee that the arcs running against the computed order are
+// few, and a vertex's id carries no meaning about where the algorithm actually placed it.
+pub(crate) fn collect_leftward_edges(graph: &HashTable, ordering: Vec<VertexId>) -> HashSet<Edge> {
+  let position: HashMap<VertexId, usize> = ordering
+    .iter()
+    .enumerate()
+    .map(|(index, &v)| (v, index))
+    .collect();
+
   let mut leftward_edges = HashSet::new();
 
   for v in ordering {
     for edge in graph.edges(v, Direction::Outbound) {
-      if edge.1 < v {
+      if position[&edge.1] < position[&edge.0] {
         leftward_edges.insert(edge);
       }
     }
@@ -81,14 +90,7 @@ fn order(mut g: HashTable) -> Vec<VertexId> {
 }
 
 fn subgraph(graph: &HashTable, vertices_to_keep: &[VertexId]) -> HashTable {
-  let edges = graph
-    .vertices()
-    .into_iter()
-    .flat_map(|v| graph.edges(v, Direction::Outbound))
-    .filter(|edge| vertices_to_keep.contains(&edge.0) && vertices_to_keep.contains(&edge.1))
-    .collect::<Vec<_>>();
-
-  HashTable::from_edges(edges.as_slice())
+  HashTable::from_graph(graph, vertices_to_keep)
 }
 
 fn vertex_with_min_indegree(graph: &HashTable) -> VertexId {
@@ -0,0 +1,3 @@
+pub mod divide_and_conquer_by_order_heuristic;
+pub mod greedy_gr_heuristic;
+pub mod scc_decomposition;
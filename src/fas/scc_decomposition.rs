@@ -0,0 +1,312 @@
+use crate::algo::greedy_heuristic::GreedyHeuristic;
+use crate::feedback_arc_set::FeedbackArcSet;
+use crate::graph::hash_table::{Direction, Edge, HashTable, VertexId};
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use std::collections::{HashMap, HashSet};
+
+/*
+Tarjan's algorithm (1972) finds the strongly connected components of a directed graph in a
+single DFS. Every vertex is pushed onto an explicit stack as it is first visited and keeps
+an `index` (DFS discovery order) and a `lowlink` (the smallest index reachable from it via
+the DFS tree plus at most one back/cross edge to a vertex still on the stack). A vertex is
+the root of an SCC exactly when its lowlink equals its own index, at which point the stack
+is popped down to it to emit that component.
+
+Edges that cross between two SCCs can never lie on a cycle, so the feedback arc set of the
+whole graph is exactly the union of the feedback arc sets of its non-trivial SCCs (size >= 2,
+or a single vertex with a self-loop). Running the heuristics on each component independently
+instead of the whole graph turns one large sparse instance into many small ones.
+*/
+pub fn strongly_connected_components(graph: &HashTable) -> Vec<Vec<VertexId>> {
+  Tarjan::new(graph).run()
+}
+
+struct Tarjan<'a> {
+  graph: &'a HashTable,
+  counter: usize,
+  index: HashMap<VertexId, usize>,
+  lowlink: HashMap<VertexId, usize>,
+  on_stack: HashSet<VertexId>,
+  stack: Vec<VertexId>,
+  components: Vec<Vec<VertexId>>,
+}
+
+impl<'a> Tarjan<'a> {
+  fn new(graph: &'a HashTable) -> Self {
+    Self {
+      graph,
+      counter: 0,
+      index: HashMap::new(),
+      lowlink: HashMap::new(),
+      on_stack: HashSet::new(),
+      stack: Vec::new(),
+      components: Vec::new(),
+    }
+  }
+
+  fn run(mut self) -> Vec<Vec<VertexId>> {
+    for v in self.graph.vertices() {
+      if !self.index.contains_key(&v) {
+        self.strong_connect(v);
+      }
+    }
+    self.components
+  }
+
+  // Iterative rewrite of the textbook recursive DFS: the PACE instances this is meant for
+  // are large and sparse enough that a recursive walk (one native stack frame per tree
+  // edge) can blow the stack, so each recursive call is instead an explicit `Frame` pushed
+  // onto `frames`. A frame remembers which neighbour it was about to visit next so the
+  // "call" can be resumed exactly where it left off, and `parent` records who to propagate
+  // the finished vertex's lowlink into once it's done.
+  fn strong_connect(&mut self, start: VertexId) {
+    let mut frames = vec![self.visit(start, None)];
+
+    while let Some(frame) = frames.last_mut() {
+      if frame.next < frame.neighbours.len() {
+        let v = frame.vertex;
+        let w = frame.neighbours[frame.next];
+        frame.next += 1;
+
+        if !self.index.contains_key(&w) {
+          frames.push(self.visit(w, Some(v)));
+        } else if self.on_stack.contains(&w) {
+          let index_w = self.index[&w];
+          let lowlink_v = self.lowlink[&v];
+          self.lowlink.insert(v, lowlink_v.min(index_w));
+        }
+      } else {
+        let finished = frames.pop().unwrap();
+        let v = finished.vertex;
+
+        if self.lowlink[&v] == self.index[&v] {
+          let mut component = Vec::new();
+          loop {
+            let w = self.stack.pop().expect("root of an SCC must be on the stack");
+            self.on_stack.remove(&w);
+            component.push(w);
+            if w == v {
+              break;
+            }
+          }
+          self.components.push(component);
+        }
+
+        if let Some(parent) = finished.parent {
+          let lowlink_v = self.lowlink[&v];
+          let lowlink_parent = self.lowlink[&parent];
+          self.lowlink.insert(parent, lowlink_parent.min(lowlink_v));
+        }
+      }
+    }
+  }
+
+  fn visit(&mut self, v: VertexId, parent: Option<VertexId>) -> Frame {
+    self.index.insert(v, self.counter);
+    self.lowlink.insert(v, self.counter);
+    self.counter += 1;
+    self.stack.push(v);
+    self.on_stack.insert(v);
+
+    let neighbours = self
+      .graph
+      .edges(v, Direction::Outbound)
+      .into_iter()
+      .map(|(_, w)| w)
+      .collect();
+
+    Frame {
+      vertex: v,
+      neighbours,
+      next: 0,
+      parent,
+    }
+  }
+}
+
+struct Frame {
+  vertex: VertexId,
+  neighbours: Vec<VertexId>,
+  next: usize,
+  parent: Option<VertexId>,
+}
+
+fn is_non_trivial(graph: &HashTable, component: &[VertexId]) -> bool {
+  component.len() >= 2 || (component.len() == 1 && graph.has_edge(component[0], component[0]))
+}
+
+/// Splits `graph` into its strongly connected components and runs `solve` on the induced
+/// subgraph of every non-trivial one, folding the results into a single feedback arc set.
+/// This is exact: edges between different SCCs can never be part of a cycle.
+///
+/// A single-vertex component with a self-loop is non-trivial (the self-loop is itself a
+/// cycle) but no vertex ordering can ever place a vertex before itself, so none of the
+/// leftward-edge heuristics can remove it; its self-loop is therefore added to the result
+/// directly instead of being hopelessly handed to `solve`.
+pub fn decompose_and_solve<F>(graph: &HashTable, mut solve: F) -> HashSet<Edge>
+where
+  F: FnMut(&HashTable) -> HashSet<Edge>,
+{
+  let mut feedback_arc_set = HashSet::new();
+
+  for component in strongly_connected_components(graph) {
+    if component.len() == 1 {
+      let v = component[0];
+      if graph.has_edge(v, v) {
+        feedback_arc_set.insert((v, v));
+      }
+      continue;
+    }
+
+    let mut subgraph = HashTable::from_graph(graph, component.as_slice());
+    // A self-loop can occur on any vertex of a non-trivial SCC, not only on a singleton
+    // one, and no leftward-edge heuristic can ever remove `(v, v)` (a vertex can't precede
+    // itself in any ordering). Strip those directly instead of handing them to `solve`.
+    for &v in &component {
+      if subgraph.has_edge(v, v) {
+        subgraph.remove_edge((v, v));
+        feedback_arc_set.insert((v, v));
+      }
+    }
+
+    feedback_arc_set.extend(solve(&subgraph));
+  }
+
+  feedback_arc_set
+}
+
+/// Same as [`decompose_and_solve`] but for the trait-based, petgraph-backed heuristics such
+/// as [`GreedyHeuristic`]. Each non-trivial SCC is converted to a `DiGraph` before being
+/// handed to the algorithm, and the resulting edges are translated back to [`VertexId`]s.
+pub fn decompose_and_solve_with_heuristic<A: FeedbackArcSet>(
+  graph: &HashTable,
+  algorithm: &A,
+) -> HashSet<Edge> {
+  decompose_and_solve(graph, |subgraph| {
+    let (petgraph, node_for_index) = to_petgraph(subgraph);
+    algorithm
+      .compute(&petgraph)
+      .into_iter()
+      .map(|edge| (node_for_index[&edge.source()], node_for_index[&edge.target()]))
+      .collect()
+  })
+}
+
+fn to_petgraph(graph: &HashTable) -> (DiGraph<VertexId, ()>, HashMap<NodeIndex, VertexId>) {
+  let mut petgraph = DiGraph::new();
+  let mut index_for_vertex = HashMap::new();
+  let mut vertex_for_index = HashMap::new();
+
+  for v in graph.vertices() {
+    let node_index = petgraph.add_node(v);
+    index_for_vertex.insert(v, node_index);
+    vertex_for_index.insert(node_index, v);
+  }
+
+  for (source, target) in graph.all_edges() {
+    petgraph.add_edge(index_for_vertex[&source], index_for_vertex[&target], ());
+  }
+
+  (petgraph, vertex_for_index)
+}
+
+/// Convenience wrapper that runs [`decompose_and_solve_with_heuristic`] with the default
+/// `GreedyHeuristic`.
+pub fn decompose_and_solve_with_greedy_heuristic(graph: &HashTable) -> HashSet<Edge> {
+  decompose_and_solve_with_heuristic(graph, &GreedyHeuristic {})
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fas::divide_and_conquer_by_order_heuristic::DivideAndConquerByOrderHeuristic;
+  use crate::tools::cycle::CycleDetection;
+
+  #[test]
+  fn splits_into_independent_cliques() {
+    let graph = HashTable::from_edges(&[
+      (0, 1),
+      (1, 2),
+      (2, 0),
+      // Bridge edge: never part of a cycle since it leaves the first component.
+      (2, 3),
+      (3, 4),
+      (4, 5),
+      (5, 3),
+    ]);
+
+    let components = strongly_connected_components(&graph);
+    let non_trivial: Vec<_> = components
+      .iter()
+      .filter(|c| is_non_trivial(&graph, c))
+      .collect();
+
+    assert_eq!(non_trivial.len(), 2);
+  }
+
+  #[test]
+  fn decompose_and_solve_with_divide_and_conquer_leaves_an_acyclic_subset() {
+    let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+    let graph = HashTable::from_edges(&edges);
+
+    let fas = decompose_and_solve(&graph, |subgraph| {
+      DivideAndConquerByOrderHeuristic::new(subgraph).feedback_arc_set()
+    });
+
+    assert!(fas.is_subset(&HashSet::from(edges)));
+
+    let mut acyclic = graph.clone();
+    for edge in &fas {
+      acyclic.remove_edge(*edge);
+    }
+    assert!(!CycleDetection::new(&acyclic).is_cyclic());
+  }
+
+  #[test]
+  fn self_loop_on_its_own_component_is_removed_even_though_no_ordering_can_break_it() {
+    // Vertex 2 forms its own singleton SCC with a self-loop; {0, 1} is a separate 2-cycle.
+    let graph = HashTable::from_edges(&[(0, 1), (1, 0), (2, 2)]);
+
+    let fas = decompose_and_solve(&graph, |subgraph| {
+      DivideAndConquerByOrderHeuristic::new(subgraph).feedback_arc_set()
+    });
+
+    assert!(fas.contains(&(2, 2)));
+
+    let mut acyclic = graph.clone();
+    for edge in &fas {
+      acyclic.remove_edge(*edge);
+    }
+    assert!(!CycleDetection::new(&acyclic).is_cyclic());
+  }
+
+  #[test]
+  fn self_loop_inside_a_larger_scc_is_removed_too() {
+    // 0, 1, 2 form one 3-cycle SCC, and vertex 1 also carries a self-loop.
+    let graph = HashTable::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 1)]);
+
+    let fas = decompose_and_solve(&graph, |subgraph| {
+      DivideAndConquerByOrderHeuristic::new(subgraph).feedback_arc_set()
+    });
+
+    assert!(fas.contains(&(1, 1)));
+
+    let mut acyclic = graph.clone();
+    for edge in &fas {
+      acyclic.remove_edge(*edge);
+    }
+    assert!(!CycleDetection::new(&acyclic).is_cyclic());
+  }
+
+  #[test]
+  fn trivial_components_contribute_nothing() {
+    let acyclic = HashTable::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+
+    let fas = decompose_and_solve(&acyclic, |subgraph| {
+      DivideAndConquerByOrderHeuristic::new(subgraph).feedback_arc_set()
+    });
+
+    assert!(fas.is_empty());
+  }
+}
@@ -0,0 +1,81 @@
+use crate::graph::hash_table::{HashTable, VertexId};
+use quickcheck::{Arbitrary, Gen};
+
+// Keep generated instances small: quickcheck runs hundreds of them per property and an
+// O(n^2) heuristic on a 100-vertex graph would make the suite crawl, while shrinking
+// benefits from having few edges to drop in the first place.
+const MAX_VERTICES: usize = 12;
+
+impl Arbitrary for HashTable {
+  fn arbitrary(g: &mut Gen) -> Self {
+    let n = usize::arbitrary(g) % MAX_VERTICES;
+    let mut graph = HashTable::new();
+    for u in 0..n as VertexId {
+      for v in 0..n as VertexId {
+        if u != v && bool::arbitrary(g) {
+          graph.add_edge((u, v));
+        }
+      }
+    }
+    graph
+  }
+
+  fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+    Box::new(
+      self
+        .all_edges()
+        .shrink()
+        .map(|edges| HashTable::from_edges(edges.as_slice())),
+    )
+  }
+}
+
+/// A `HashTable` that is guaranteed to be acyclic: a random vertex permutation is drawn and
+/// only edges pointing forward through it are ever emitted.
+#[derive(Clone, Debug)]
+pub struct RandomDag(pub HashTable);
+
+impl Arbitrary for RandomDag {
+  fn arbitrary(g: &mut Gen) -> Self {
+    let n = (usize::arbitrary(g) % MAX_VERTICES).max(1);
+    let mut order: Vec<VertexId> = (0..n as VertexId).collect();
+    for i in (1..order.len()).rev() {
+      let j = usize::arbitrary(g) % (i + 1);
+      order.swap(i, j);
+    }
+
+    let mut graph = HashTable::new();
+    for i in 0..order.len() {
+      for j in (i + 1)..order.len() {
+        if bool::arbitrary(g) {
+          graph.add_edge((order[i], order[j]));
+        }
+      }
+    }
+
+    RandomDag(graph)
+  }
+}
+
+/// A `HashTable` that is a tournament: for every unordered pair of vertices exactly one of
+/// the two directed edges between them is present, chosen at random.
+#[derive(Clone, Debug)]
+pub struct Tournament(pub HashTable);
+
+impl Arbitrary for Tournament {
+  fn arbitrary(g: &mut Gen) -> Self {
+    let n = (usize::arbitrary(g) % MAX_VERTICES).max(2);
+    let mut graph = HashTable::new();
+    for u in 0..n as VertexId {
+      for v in (u + 1)..n as VertexId {
+        if bool::arbitrary(g) {
+          graph.add_edge((u, v));
+        } else {
+          graph.add_edge((v, u));
+        }
+      }
+    }
+
+    Tournament(graph)
+  }
+}
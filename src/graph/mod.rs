@@ -0,0 +1,4 @@
+pub mod hash_table;
+
+#[cfg(feature = "quickcheck")]
+pub mod arbitrary;
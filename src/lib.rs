@@ -8,6 +8,9 @@ pub mod graph;
 pub mod ordering;
 pub mod tools;
 
+#[cfg(all(test, feature = "quickcheck"))]
+mod property_tests;
+
 #[cfg(test)]
 mod tests {
   #[test]
@@ -0,0 +1,95 @@
+use crate::graph::hash_table::{Direction, HashTable, VertexId};
+use crate::ordering::topological_sort::TopologicalSort;
+use std::collections::HashSet;
+
+/// Collects the maximal linear chains ("runs") of an acyclic `HashTable`: sequences
+/// `v1 -> v2 -> ... -> vk` where every internal vertex has exactly one relevant successor and
+/// predecessor within the run. `filter_fn` excludes vertices from being added to or used to
+/// extend a run, e.g. to keep already-scheduled vertices out of later runs. Every vertex that
+/// passes the filter ends up in exactly one run.
+pub fn collect_runs(
+  graph: &HashTable,
+  filter_fn: impl Fn(&VertexId) -> bool,
+) -> Vec<Vec<VertexId>> {
+  let ordering = TopologicalSort::new(graph).sort();
+  let mut consumed = HashSet::new();
+  let mut runs = Vec::new();
+
+  for v in ordering {
+    if consumed.contains(&v) || !filter_fn(&v) {
+      continue;
+    }
+
+    let mut run = vec![v];
+    consumed.insert(v);
+
+    let mut current = v;
+    while let Some(next) = sole_extension(graph, current, &consumed, &filter_fn) {
+      run.push(next);
+      consumed.insert(next);
+      current = next;
+    }
+
+    runs.push(run);
+  }
+
+  runs
+}
+
+fn sole_extension(
+  graph: &HashTable,
+  v: VertexId,
+  consumed: &HashSet<VertexId>,
+  filter_fn: &impl Fn(&VertexId) -> bool,
+) -> Option<VertexId> {
+  let successors = graph.edges(v, Direction::Outbound);
+  if successors.len() != 1 {
+    return None;
+  }
+
+  let (_, next) = successors[0];
+  if consumed.contains(&next) || !filter_fn(&next) {
+    return None;
+  }
+
+  if graph.edges(next, Direction::Inbound).len() != 1 {
+    return None;
+  }
+
+  Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn collects_a_single_chain() {
+    let graph = HashTable::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+
+    let runs = collect_runs(&graph, |_| true);
+
+    assert_eq!(runs, vec![vec![0, 1, 2, 3]]);
+  }
+
+  #[test]
+  fn splits_at_a_branching_vertex() {
+    // 0 -> 1 -> 2, and 0 -> 3: vertex 0 has two successors, so it can't extend into a run,
+    // but 1 -> 2 still forms its own chain.
+    let graph = HashTable::from_edges(&[(0, 1), (0, 3), (1, 2)]);
+
+    let mut runs = collect_runs(&graph, |_| true);
+    runs.sort();
+
+    assert_eq!(runs, vec![vec![0], vec![1, 2], vec![3]]);
+  }
+
+  #[test]
+  fn filter_fn_excludes_vertices_from_runs() {
+    let graph = HashTable::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+
+    let runs = collect_runs(&graph, |&v| v != 2);
+
+    assert_eq!(runs, vec![vec![0, 1], vec![3]]);
+  }
+}
@@ -0,0 +1 @@
+pub mod collect_runs;
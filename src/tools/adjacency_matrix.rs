@@ -0,0 +1,126 @@
+use crate::graph::hash_table::{HashTable, VertexId};
+use std::error::Error;
+use std::fmt;
+
+/// An adjacency matrix that is either not square or contains an entry other than `0`/`1`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AdjacencyMatrixError {
+  NotSquare { row: usize, expected: usize, found: usize },
+  InvalidEntry { row: usize, column: usize, value: String },
+}
+
+impl fmt::Display for AdjacencyMatrixError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      AdjacencyMatrixError::NotSquare { row, expected, found } => write!(
+        f,
+        "adjacency matrix must be square: row {row} has {found} entries, expected {expected}"
+      ),
+      AdjacencyMatrixError::InvalidEntry { row, column, value } => write!(
+        f,
+        "entry at ({row}, {column}) must be 0 or 1, got {value:?}"
+      ),
+    }
+  }
+}
+
+impl Error for AdjacencyMatrixError {}
+
+/// Parses a whitespace-separated 0/1 adjacency matrix where the entry in row `i`, column `j`
+/// being `1` means a directed edge `i -> j`, and builds a `HashTable` from it.
+pub fn graph_from_matrix(content: &str) -> Result<HashTable, AdjacencyMatrixError> {
+  let rows: Vec<Vec<&str>> = content
+    .lines()
+    .filter(|line| !line.trim().is_empty())
+    .map(|line| line.split_whitespace().collect())
+    .collect();
+
+  let vertex_count = rows.len();
+  let mut edges = Vec::new();
+
+  for (row_index, row) in rows.iter().enumerate() {
+    if row.len() != vertex_count {
+      return Err(AdjacencyMatrixError::NotSquare {
+        row: row_index,
+        expected: vertex_count,
+        found: row.len(),
+      });
+    }
+
+    for (column_index, entry) in row.iter().enumerate() {
+      match *entry {
+        "0" => {}
+        "1" => edges.push((row_index as VertexId, column_index as VertexId)),
+        other => {
+          return Err(AdjacencyMatrixError::InvalidEntry {
+            row: row_index,
+            column: column_index,
+            value: other.to_string(),
+          })
+        }
+      }
+    }
+  }
+
+  let vertices: Vec<VertexId> = (0..vertex_count as VertexId).collect();
+  Ok(HashTable::from_vertices_and_edges(&vertices, &edges))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_a_small_cycle() {
+    let matrix = "0 1 0\n0 0 1\n1 0 0\n";
+
+    let graph = graph_from_matrix(matrix).unwrap();
+
+    assert_eq!(graph.order(), 3);
+    assert!(graph.has_edge(0, 1));
+    assert!(graph.has_edge(1, 2));
+    assert!(graph.has_edge(2, 0));
+    assert!(!graph.has_edge(0, 2));
+  }
+
+  #[test]
+  fn keeps_isolated_vertices() {
+    let matrix = "0 0\n0 0\n";
+
+    let graph = graph_from_matrix(matrix).unwrap();
+
+    assert_eq!(graph.order(), 2);
+  }
+
+  #[test]
+  fn rejects_non_square_matrices() {
+    let matrix = "0 1\n0 0 1\n";
+
+    let error = graph_from_matrix(matrix).unwrap_err();
+
+    assert_eq!(
+      error,
+      AdjacencyMatrixError::NotSquare {
+        row: 1,
+        expected: 2,
+        found: 3,
+      }
+    );
+  }
+
+  #[test]
+  fn rejects_entries_other_than_zero_or_one() {
+    let matrix = "0 2\n1 0\n";
+
+    let error = graph_from_matrix(matrix).unwrap_err();
+
+    assert_eq!(
+      error,
+      AdjacencyMatrixError::InvalidEntry {
+        row: 0,
+        column: 1,
+        value: "2".to_string(),
+      }
+    );
+  }
+}
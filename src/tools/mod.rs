@@ -0,0 +1,2 @@
+pub mod adjacency_matrix;
+pub mod metis;